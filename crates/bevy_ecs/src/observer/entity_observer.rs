@@ -1,15 +1,29 @@
 use crate::{
-    component::{Component, ComponentCloneBehavior, Mutable, StorageType},
-    entity::{ComponentCloneCtx, Entity, EntityClonerBuilder, EntityMapper, SourceComponent},
+    bundle::Bundle,
+    component::{Component, ComponentCloneBehavior, ComponentId, Immutable, Mutable, StorageType},
+    entity::{
+        ComponentCloneCtx, Entity, EntityCloner, EntityClonerBuilder, EntityMapper, SourceComponent,
+    },
+    event::{EntityEvent, Event},
     lifecycle::{ComponentHook, HookContext},
-    world::World,
+    query::{QueryFilter, QueryState},
+    resource::Resource,
+    system::Commands,
+    world::{DeferredWorld, EntityWorldMut, World},
 };
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::sync::mpsc::Sender;
 
 #[cfg(feature = "bevy_reflect")]
 use crate::prelude::ReflectComponent;
 
-use super::Observer;
+use super::{IntoObserverSystem, Observer, On};
 
 /// Tracks a list of entity observers for the [`Entity`] [`ObservedBy`] is added to.
 #[derive(Default, Debug)]
@@ -62,6 +76,401 @@ impl Component for ObservedBy {
     }
 }
 
+impl World {
+    /// Spawns an [`Observer`] for event `E` that forwards a clone of every triggered event to
+    /// `sender` instead of running a system.
+    ///
+    /// This lets code outside the schedule, such as a render thread, an async task, or editor
+    /// tooling, consume observer events without itself being a system. Once the receiving end of
+    /// the channel is dropped, the next event delivery fails to send and the observer despawns
+    /// itself rather than keep forwarding events nobody can read.
+    #[cfg(feature = "std")]
+    pub fn observe_into_channel<E: Event + Clone + Send>(
+        &mut self,
+        sender: Sender<E>,
+    ) -> EntityWorldMut<'_> {
+        self.add_observer(move |on: On<E>, mut commands: Commands| {
+            if sender.send(on.event().clone()).is_err() {
+                commands.entity(on.observer()).despawn();
+            }
+        })
+    }
+
+    /// Spawns an [`Observer`] for event `E` whose watched entity set is kept in sync with the
+    /// archetype filter `F`, instead of being fixed to the entities passed to `with_entity`/
+    /// `watch_entity` at creation time.
+    ///
+    /// Whenever an entity starts matching `F` it is added to the observer's
+    /// [`descriptor.entities`](Observer) and gains an [`ObservedBy`] entry; whenever it stops
+    /// matching, both are undone. This reuses the same bookkeeping that
+    /// [`component_clone_observed_by`] maintains when cloning entities, just driven by component
+    /// hooks on the components that make up `F` instead of by `EntityClonerBuilder`.
+    ///
+    /// This keeps track of `F`'s filtered components by claiming their lifecycle hooks, the same
+    /// way [`World::index_by_value`] claims the hooks of the component it indexes; mixing the two
+    /// on the same component (in either order) panics with a clear message instead of failing
+    /// deep inside `ComponentHooks`.
+    pub fn add_observer_filtered<E: Event, B: Bundle, F: QueryFilter + 'static, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> EntityWorldMut<'_> {
+        let observer_entity = self.add_observer(observer).id();
+
+        let mut query_state = QueryState::<Entity, F>::new(self);
+        let initial_matches: Vec<Entity> = query_state.iter(self).collect();
+        let access = query_state.component_access();
+        let with_ids: Arc<[ComponentId]> = access.with_filters().collect::<Vec<_>>().into();
+        let without_ids: Arc<[ComponentId]> = access.without_filters().collect::<Vec<_>>().into();
+        let link = FilteredObserverLink {
+            observer_entity,
+            with_ids: with_ids.clone(),
+            without_ids: without_ids.clone(),
+        };
+
+        self.init_resource::<FilteredObserverIndex>();
+        let mut newly_watched_components = Vec::new();
+        {
+            let mut index = self.resource_mut::<FilteredObserverIndex>();
+            for &component_id in with_ids.iter().chain(without_ids.iter()) {
+                index.0.entry(component_id).or_default().push(link.clone());
+                if index.1.insert(component_id) {
+                    newly_watched_components.push(component_id);
+                }
+            }
+        }
+        for component_id in newly_watched_components {
+            claim_component_hooks(self, component_id);
+            self.register_component_hooks_by_id(component_id)
+                .on_insert(sync_filtered_observers_on_insert)
+                .on_remove(sync_filtered_observers_on_remove);
+        }
+
+        // The hooks above only catch matches from this point on; entities that already matched
+        // `F` when the observer was created need to be seeded in, so this behaves like "observe
+        // the current query results" rather than "observe future matches" only.
+        if !initial_matches.is_empty() {
+            if let Some(mut observer_state) = self.get_mut::<Observer>(observer_entity) {
+                observer_state
+                    .descriptor
+                    .entities
+                    .extend(initial_matches.iter().copied());
+            }
+            for entity in initial_matches {
+                if let Some(mut observed_by) = self.get_mut::<ObservedBy>(entity) {
+                    observed_by.0.push(observer_entity);
+                } else {
+                    self.entity_mut(entity)
+                        .insert(ObservedBy(alloc::vec![observer_entity]));
+                }
+            }
+        }
+
+        self.entity_mut(observer_entity)
+            .insert(FilteredObserverMarker {
+                with_ids,
+                without_ids,
+            })
+    }
+
+    /// Builds (or, if already built, reuses) a secondary index grouping entities by a value
+    /// derived from their `C` component, so they can be found by that value with an O(log n)
+    /// lookup in a sorted map instead of scanning archetypes.
+    ///
+    /// `key_fn` is re-evaluated on every insert and replace of `C`, and an entity is dropped from
+    /// the index the moment its `C` is removed. Changing `C` in place (for example through
+    /// `get_mut`) runs none of those hooks, so the entity stays grouped under the key it had at
+    /// its last insert. Look the groups up with [`World::entities_with_value`], or trigger an
+    /// event at exactly the matching entities with [`World::trigger_targets_with_value`].
+    ///
+    /// Only one key type `K` can be indexed per component `C`, because `C`'s lifecycle hooks can
+    /// only be registered once; calling this a second time for the same `C` with a different `K`
+    /// panics rather than silently failing deep inside `ComponentHooks`. The same restriction
+    /// applies across features: calling this for a component already claimed by
+    /// [`World::add_observer_filtered`] (or vice versa) panics too.
+    pub fn index_by_value<C: Component, K: Ord + Clone + Send + Sync + 'static>(
+        &mut self,
+        key_fn: impl Fn(&C) -> K + Send + Sync + 'static,
+    ) -> &mut Self {
+        if self.contains_resource::<ComponentValueIndex<C, K>>() {
+            return self;
+        }
+        assert!(
+            !self.contains_resource::<ValueIndexed<C>>(),
+            "World::index_by_value::<{}, _> was already called with a different key type; \
+             only one key type can be indexed per component",
+            core::any::type_name::<C>(),
+        );
+        self.insert_resource(ValueIndexed::<C>::default());
+        self.insert_resource(ComponentValueIndex::<C, K> {
+            by_key: BTreeMap::new(),
+            key_of: BTreeMap::new(),
+            key_fn: Box::new(key_fn),
+        });
+        let component_id = self.register_component::<C>();
+        claim_component_hooks(self, component_id);
+        self.register_component_hooks::<C>()
+            .on_insert(on_indexed_value_inserted::<C, K>)
+            .on_replace(on_indexed_value_removed::<C, K>)
+            .on_remove(on_indexed_value_removed::<C, K>);
+        self
+    }
+
+    /// Returns the entities whose `C` component currently maps to `key`, as registered with
+    /// [`World::index_by_value`].
+    ///
+    /// Returns an empty slice if no such index has been built.
+    pub fn entities_with_value<C: Component, K: Ord + Clone + Send + Sync + 'static>(
+        &self,
+        key: &K,
+    ) -> &[Entity] {
+        self.get_resource::<ComponentValueIndex<C, K>>()
+            .and_then(|index| index.by_key.get(key))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Triggers `event` only at the entities currently indexed under `key`, using
+    /// [`World::entities_with_value`] instead of running every `C`-scoped observer just to have
+    /// it early-return.
+    pub fn trigger_targets_with_value<
+        E: EntityEvent,
+        C: Component,
+        K: Ord + Clone + Send + Sync + 'static,
+    >(
+        &mut self,
+        event: E,
+        key: &K,
+    ) {
+        let targets = self.entities_with_value::<C, K>(key).to_vec();
+        if !targets.is_empty() {
+            self.trigger_targets(event, targets);
+        }
+    }
+}
+
+/// Marks that [`World::index_by_value`] has already registered `C`'s lifecycle hooks, regardless
+/// of which key type they were registered with. `C`'s hooks can only be claimed once, so this is
+/// checked up front to turn a second, differently-keyed call into a clear panic instead of a
+/// confusing one from deep inside `ComponentHooks`.
+#[derive(Resource)]
+struct ValueIndexed<C>(core::marker::PhantomData<C>);
+
+impl<C> Default for ValueIndexed<C> {
+    fn default() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+/// Tracks every [`ComponentId`] whose lifecycle hooks have already been claimed by either
+/// [`World::index_by_value`] or [`World::add_observer_filtered`]. A component's hooks can only be
+/// registered once, so this is checked up front by both so that combining them on the same
+/// component produces one clear panic here instead of a confusing one from deep inside
+/// `ComponentHooks`.
+#[derive(Resource, Default)]
+struct ClaimedComponentHooks(BTreeSet<ComponentId>);
+
+fn claim_component_hooks(world: &mut World, component_id: ComponentId) {
+    let mut claimed = world.get_resource_or_insert_with(ClaimedComponentHooks::default);
+    assert!(
+        claimed.0.insert(component_id),
+        "a component's lifecycle hooks were already claimed by `World::index_by_value` or \
+         `World::add_observer_filtered`; only one of them can own a given component's hooks \
+         at a time",
+    );
+}
+
+/// Backs [`World::index_by_value`]: groups entities by a value derived from their `C` component.
+#[derive(Resource)]
+struct ComponentValueIndex<C: Component, K> {
+    by_key: BTreeMap<K, Vec<Entity>>,
+    key_of: BTreeMap<Entity, K>,
+    key_fn: Box<dyn Fn(&C) -> K + Send + Sync>,
+}
+
+impl<C: Component, K: Ord + Clone + Send + Sync + 'static> ComponentValueIndex<C, K> {
+    fn insert_entity(&mut self, entity: Entity, key: K) {
+        self.remove_entity(entity);
+        self.by_key.entry(key.clone()).or_default().push(entity);
+        self.key_of.insert(entity, key);
+    }
+
+    fn remove_entity(&mut self, entity: Entity) {
+        let Some(key) = self.key_of.remove(&entity) else {
+            return;
+        };
+        if let Some(entities) = self.by_key.get_mut(&key) {
+            entities.retain(|&watched| watched != entity);
+            if entities.is_empty() {
+                self.by_key.remove(&key);
+            }
+        }
+    }
+}
+
+fn on_indexed_value_inserted<C: Component, K: Ord + Clone + Send + Sync + 'static>(
+    mut world: DeferredWorld,
+    HookContext { entity, .. }: HookContext,
+) {
+    let Some(key) = world
+        .get::<C>(entity)
+        .zip(world.get_resource::<ComponentValueIndex<C, K>>())
+        .map(|(value, index)| (index.key_fn)(value))
+    else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentValueIndex<C, K>>() {
+        index.insert_entity(entity, key);
+    }
+}
+
+fn on_indexed_value_removed<C: Component, K: Ord + Clone + Send + Sync + 'static>(
+    mut world: DeferredWorld,
+    HookContext { entity, .. }: HookContext,
+) {
+    if let Some(mut index) = world.get_resource_mut::<ComponentValueIndex<C, K>>() {
+        index.remove_entity(entity);
+    }
+}
+
+/// One archetype-filtered observer's watch condition, indexed by every [`ComponentId`] that
+/// appears in its filter so [`sync_filtered_observers`] can re-evaluate it from a single hook.
+#[derive(Clone)]
+struct FilteredObserverLink {
+    observer_entity: Entity,
+    with_ids: Arc<[ComponentId]>,
+    without_ids: Arc<[ComponentId]>,
+}
+
+/// Maps a [`ComponentId`] referenced by a filtered observer to the observers that need
+/// re-evaluating whenever that component is added to or removed from an entity.
+///
+/// The second field records every [`ComponentId`] whose hooks have been registered for filtered
+/// observers. It is never pruned, since those hooks stay registered after the last observer using
+/// them despawns, so a later filtered observer on the same component reuses them.
+#[derive(Resource, Default)]
+struct FilteredObserverIndex(
+    BTreeMap<ComponentId, Vec<FilteredObserverLink>>,
+    BTreeSet<ComponentId>,
+);
+
+/// Attached to every observer entity created by [`World::add_observer_filtered`] purely so its
+/// `on_remove` hook can scrub that observer's [`FilteredObserverLink`] entries out of
+/// [`FilteredObserverIndex`] once the observer entity is despawned. Without this, despawning a
+/// filtered observer (directly, or via [`ObservedBy::on_remove`]'s own despawn-when-unwatched
+/// bookkeeping) would leave its links behind forever, so every future insert or remove of a
+/// filtered component anywhere in the `World` keeps re-evaluating against dead entities.
+struct FilteredObserverMarker {
+    with_ids: Arc<[ComponentId]>,
+    without_ids: Arc<[ComponentId]>,
+}
+
+impl Component for FilteredObserverMarker {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+    type Mutability = Immutable;
+
+    fn on_remove() -> Option<ComponentHook> {
+        Some(|mut world, HookContext { entity, .. }| {
+            let Some(marker) = world.get::<FilteredObserverMarker>(entity) else {
+                return;
+            };
+            let with_ids = marker.with_ids.clone();
+            let without_ids = marker.without_ids.clone();
+
+            let Some(mut index) = world.get_resource_mut::<FilteredObserverIndex>() else {
+                return;
+            };
+            for component_id in with_ids.iter().chain(without_ids.iter()) {
+                let Some(links) = index.0.get_mut(component_id) else {
+                    continue;
+                };
+                links.retain(|link| link.observer_entity != entity);
+                if links.is_empty() {
+                    index.0.remove(component_id);
+                }
+            }
+        })
+    }
+
+    fn clone_behavior() -> ComponentCloneBehavior {
+        ComponentCloneBehavior::Ignore
+    }
+}
+
+fn sync_filtered_observers_on_insert(world: DeferredWorld, context: HookContext) {
+    sync_filtered_observers(world, context, None);
+}
+
+fn sync_filtered_observers_on_remove(world: DeferredWorld, context: HookContext) {
+    let removing = context.component_id;
+    sync_filtered_observers(world, context, Some(removing));
+}
+
+/// `removing` is the [`ComponentId`] whose `on_remove` hook is driving this call, if any. It is
+/// still reported present by `EntityRef::contains_id` while `on_remove` runs (the removal hasn't
+/// taken effect yet, which is exactly what lets [`ObservedBy::on_remove`] above read its own
+/// component during removal), so callers in the removal path must pass it here rather than rely
+/// on `contains_id` to already reflect the pending removal.
+fn sync_filtered_observers(
+    mut world: DeferredWorld,
+    context: HookContext,
+    removing: Option<ComponentId>,
+) {
+    let Some(index) = world.get_resource::<FilteredObserverIndex>() else {
+        return;
+    };
+    let Some(links) = index.0.get(&context.component_id).cloned() else {
+        return;
+    };
+    for link in &links {
+        sync_filtered_observer_entity(&mut world, link, context.entity, removing);
+    }
+}
+
+fn sync_filtered_observer_entity(
+    world: &mut DeferredWorld,
+    link: &FilteredObserverLink,
+    entity: Entity,
+    removing: Option<ComponentId>,
+) {
+    let Ok(target) = world.get_entity(entity) else {
+        return;
+    };
+    let is_present = |id: ComponentId| Some(id) != removing && target.contains_id(id);
+    let matches = link.with_ids.iter().all(|&id| is_present(id))
+        && link.without_ids.iter().all(|&id| !is_present(id));
+
+    let Some(mut observer_state) = world.get_mut::<Observer>(link.observer_entity) else {
+        return;
+    };
+    let already_watching = observer_state.descriptor.entities.contains(&entity);
+    if matches == already_watching {
+        return;
+    }
+
+    if matches {
+        observer_state.descriptor.entities.push(entity);
+    } else {
+        observer_state
+            .descriptor
+            .entities
+            .retain(|&watched| watched != entity);
+    }
+    drop(observer_state);
+
+    if let Some(mut observed_by) = world.get_mut::<ObservedBy>(entity) {
+        if matches {
+            observed_by.0.push(link.observer_entity);
+        } else {
+            observed_by.0.retain(|&o| o != link.observer_entity);
+        }
+    } else if matches {
+        world
+            .commands()
+            .entity(entity)
+            .insert(ObservedBy(alloc::vec![link.observer_entity]));
+    }
+}
+
 impl EntityClonerBuilder<'_> {
     /// Sets the option to automatically add cloned entities to the observers targeting source entity.
     pub fn add_observers(&mut self, add_observers: bool) -> &mut Self {
@@ -73,6 +482,30 @@ impl EntityClonerBuilder<'_> {
             self.remove_clone_behavior_override::<ObservedBy>()
         }
     }
+
+    /// Sets the option to give the cloned entity its own independent copy of the observers
+    /// targeting the source entity, rather than appending the clone to the source's existing
+    /// observers.
+    ///
+    /// For each [`Observer`] in the source's [`ObservedBy`] that watches only the source entity,
+    /// this attempts to clone the observer entity through the same [`EntityCloner`] machinery as
+    /// any other entity, then rewrites the clone's `descriptor.entities` to watch the target
+    /// instead of the source. Duplicating an [`Observer`] this way only works if its own captured
+    /// system supports it; most systems registered through `.observe(...)` are plain closures and
+    /// don't. And an observer that watches more than just the source entity can't be split into
+    /// two independent copies without deciding which of its other watches should follow the
+    /// clone. In both of those cases, this falls back to the same sharing behavior as
+    /// [`add_observers(true)`](Self::add_observers) instead of silently leaving the clone without
+    /// that observer.
+    pub fn add_observers_deep(&mut self, add_observers: bool) -> &mut Self {
+        if add_observers {
+            self.override_clone_behavior::<ObservedBy>(ComponentCloneBehavior::Custom(
+                component_clone_observed_by_deep,
+            ))
+        } else {
+            self.remove_clone_behavior_override::<ObservedBy>()
+        }
+    }
 }
 
 fn component_clone_observed_by(_source: &SourceComponent, ctx: &mut ComponentCloneCtx) {
@@ -120,9 +553,126 @@ fn component_clone_observed_by(_source: &SourceComponent, ctx: &mut ComponentClo
     });
 }
 
+fn component_clone_observed_by_deep(_source: &SourceComponent, ctx: &mut ComponentCloneCtx) {
+    let target = ctx.target();
+    let source = ctx.source();
+
+    ctx.queue_deferred(move |world: &mut World, _mapper: &mut dyn EntityMapper| {
+        let observed_by = world
+            .get::<ObservedBy>(source)
+            .map(|observed_by| observed_by.0.clone())
+            .expect("Source entity must have ObservedBy");
+
+        let mut cloned_observers = Vec::with_capacity(observed_by.len());
+
+        for source_observer in observed_by.iter().copied() {
+            let only_watches_source = world
+                .get::<Observer>(source_observer)
+                .expect("Source observer entity must have Observer")
+                .descriptor
+                .entities
+                == [source];
+
+            // An observer that watches entities besides `source` can't be split into an
+            // independent copy without deciding which of those other watches should follow the
+            // clone, so don't even attempt to duplicate it; fall straight through to sharing.
+            let mut duplicated_entity = None;
+            if only_watches_source {
+                let scratch = world.spawn_empty().id();
+                EntityCloner::build(world).clone_entity(source_observer, scratch);
+                // Goes through the same `EntityCloner` machinery as any other entity clone,
+                // rather than assuming `Observer` implements `Clone` itself. Whether that
+                // machinery actually duplicates `Observer`'s boxed system depends entirely on
+                // `Observer`'s own `Component::clone_behavior`; plain closures registered through
+                // `.observe` have no general way to support this.
+                if world.get::<Observer>(scratch).is_some() {
+                    duplicated_entity = Some(scratch);
+                } else {
+                    world.despawn(scratch);
+                }
+            }
+
+            let observer_entity = if let Some(duplicated_entity) = duplicated_entity {
+                let mut observer_state = world.get_mut::<Observer>(duplicated_entity).unwrap();
+                observer_state.descriptor.entities = alloc::vec![target];
+                observer_state.despawned_watched_entities = 0;
+                duplicated_entity
+            } else {
+                // There's no independent copy to give `target`, either because `Observer`'s
+                // system doesn't support duplication or because this observer watches more than
+                // just `source`, so fall back to sharing the original observer between `source`
+                // and `target`, the same as `add_observers(true)` does, instead of silently
+                // leaving `target` without it.
+                let mut observer_state = world
+                    .get_mut::<Observer>(source_observer)
+                    .expect("Source observer entity must have Observer");
+                observer_state.descriptor.entities.push(target);
+                source_observer
+            };
+
+            let event_types = world
+                .get::<Observer>(observer_entity)
+                .unwrap()
+                .descriptor
+                .events
+                .clone();
+            let components = world
+                .get::<Observer>(observer_entity)
+                .unwrap()
+                .descriptor
+                .components
+                .clone();
+
+            for event_type in event_types {
+                let observers = world.observers.get_observers_mut(event_type);
+                if components.is_empty() {
+                    if let Some(runner) = observers
+                        .entity_observers
+                        .get(&source)
+                        .and_then(|runners| runners.get(&source_observer))
+                        .cloned()
+                    {
+                        observers
+                            .entity_observers
+                            .entry(target)
+                            .or_default()
+                            .insert(observer_entity, runner);
+                    }
+                } else {
+                    for component in &components {
+                        let Some(observers) = observers.component_observers.get_mut(component)
+                        else {
+                            continue;
+                        };
+                        if let Some(runner) = observers
+                            .entity_component_observers
+                            .get(&source)
+                            .and_then(|runners| runners.get(&source_observer))
+                            .cloned()
+                        {
+                            observers
+                                .entity_component_observers
+                                .entry(target)
+                                .or_default()
+                                .insert(observer_entity, runner);
+                        }
+                    }
+                }
+            }
+
+            cloned_observers.push(observer_entity);
+        }
+
+        world
+            .entity_mut(target)
+            .insert(ObservedBy(cloned_observers));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
+        component::Component,
         entity::EntityCloner,
         event::{EntityEvent, Event},
         observer::On,
@@ -159,4 +709,280 @@ mod tests {
 
         assert_eq!(world.resource::<Num>().0, 3);
     }
+
+    #[test]
+    fn deep_clone_entity_with_observer_falls_back_to_sharing_when_not_duplicable() {
+        use super::ObservedBy;
+
+        let mut world = World::default();
+        world.init_resource::<Num>();
+
+        let e = world
+            .spawn_empty()
+            .observe(|_: On<E>, mut res: ResMut<Num>| res.0 += 1)
+            .id();
+        world.flush();
+
+        let e_clone = world.spawn_empty().id();
+        EntityCloner::build(&mut world)
+            .add_observers_deep(true)
+            .clone_entity(e, e_clone);
+        world.flush();
+
+        // Plain closures registered through `.observe` don't support independent duplication, so
+        // this falls back to sharing the same observer between `e` and `e_clone` (the same
+        // observer entity appears in both `ObservedBy`s) rather than silently giving `e_clone`
+        // no observer at all.
+        let source_observers = world.get::<ObservedBy>(e).unwrap().get().to_vec();
+        let clone_observers = world.get::<ObservedBy>(e_clone).unwrap().get().to_vec();
+        assert_eq!(source_observers, clone_observers);
+
+        world.trigger_targets(E, [e, e_clone]);
+
+        assert_eq!(world.resource::<Num>().0, 2);
+    }
+
+    #[test]
+    fn deep_clone_shares_observer_that_watches_more_than_just_source() {
+        use crate::query::With;
+
+        let mut world = World::default();
+        world.init_resource::<Num>();
+
+        world.add_observer_filtered::<E, (), With<A>, _>(|_: On<E>, mut res: ResMut<Num>| {
+            res.0 += 1;
+        });
+        world.flush();
+
+        let a = world.spawn(A).id();
+        let b = world.spawn(A).id();
+        world.flush();
+
+        let a_clone = world.spawn_empty().id();
+        EntityCloner::build(&mut world)
+            .add_observers_deep(true)
+            .clone_entity(a, a_clone);
+        world.flush();
+
+        // The observer watches both `a` and `b`, so there's no well-defined independent copy to
+        // give `a_clone`; it falls back to sharing the same observer, which keeps watching `a`
+        // and `b` and now watches `a_clone` too.
+        world.trigger_targets(E, [a, b, a_clone]);
+
+        assert_eq!(world.resource::<Num>().0, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn observe_into_channel_forwards_events() {
+        use std::sync::mpsc::channel;
+
+        let mut world = World::default();
+        let (sender, receiver) = channel();
+        world.observe_into_channel::<E>(sender);
+        world.flush();
+
+        world.trigger(E);
+        world.trigger(E);
+
+        assert_eq!(receiver.try_iter().count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn observe_into_channel_despawns_when_receiver_dropped() {
+        use std::sync::mpsc::channel;
+
+        let mut world = World::default();
+        let (sender, receiver) = channel();
+        let observer = world.observe_into_channel::<E>(sender).id();
+        world.flush();
+
+        drop(receiver);
+        world.trigger(E);
+        world.flush();
+
+        assert!(world.get_entity(observer).is_err());
+    }
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn filtered_observer_tracks_entities_entering_and_leaving_filter() {
+        use crate::query::{With, Without};
+
+        let mut world = World::default();
+        world.init_resource::<Num>();
+
+        world.add_observer_filtered::<E, (), (With<A>, Without<B>), _>(
+            |_: On<E>, mut res: ResMut<Num>| res.0 += 1,
+        );
+        world.flush();
+
+        let matching = world.spawn(A).id();
+        let excluded = world.spawn((A, B)).id();
+        let unrelated = world.spawn_empty().id();
+        world.flush();
+
+        world.trigger_targets(E, [matching, excluded, unrelated]);
+        assert_eq!(world.resource::<Num>().0, 1);
+
+        world.entity_mut(excluded).remove::<B>();
+        world.flush();
+
+        world.trigger_targets(E, [matching, excluded, unrelated]);
+        assert_eq!(world.resource::<Num>().0, 3);
+
+        world.entity_mut(matching).remove::<A>();
+        world.flush();
+
+        world.trigger_targets(E, [matching, excluded, unrelated]);
+        assert_eq!(world.resource::<Num>().0, 4);
+    }
+
+    #[test]
+    fn filtered_observer_seeds_entities_that_already_match_on_creation() {
+        use crate::query::{With, Without};
+
+        let mut world = World::default();
+        world.init_resource::<Num>();
+
+        let matching = world.spawn(A).id();
+        let excluded = world.spawn((A, B)).id();
+        world.flush();
+
+        world.add_observer_filtered::<E, (), (With<A>, Without<B>), _>(
+            |_: On<E>, mut res: ResMut<Num>| res.0 += 1,
+        );
+        world.flush();
+
+        world.trigger_targets(E, [matching, excluded]);
+
+        assert_eq!(world.resource::<Num>().0, 1);
+    }
+
+    #[test]
+    fn filtered_observer_link_is_removed_when_observer_despawns() {
+        use super::FilteredObserverIndex;
+        use crate::query::With;
+
+        let mut world = World::default();
+        world.init_resource::<Num>();
+
+        let observer = world
+            .add_observer_filtered::<E, (), With<A>, _>(|_: On<E>, mut res: ResMut<Num>| {
+                res.0 += 1;
+            })
+            .id();
+        world.flush();
+
+        assert!(!world.resource::<FilteredObserverIndex>().0.is_empty());
+
+        world.despawn(observer);
+        world.flush();
+
+        // The observer's link must be scrubbed out, not just left pointing at a dead entity:
+        // otherwise every future match of `With<A>` keeps re-evaluating against it forever.
+        assert!(world.resource::<FilteredObserverIndex>().0.is_empty());
+
+        // Matching the filter again must not panic now that the observer is gone.
+        world.spawn(A);
+        world.flush();
+    }
+
+    #[derive(Component)]
+    struct Team(&'static str);
+
+    #[test]
+    fn value_index_groups_and_regroups_entities_as_component_changes() {
+        let mut world = World::default();
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+
+        let red = world.spawn(Team("red")).id();
+        let also_red = world.spawn(Team("red")).id();
+        let blue = world.spawn(Team("blue")).id();
+        world.flush();
+
+        assert_eq!(
+            world.entities_with_value::<Team, _>(&"red"),
+            [red, also_red]
+        );
+        assert_eq!(world.entities_with_value::<Team, _>(&"blue"), [blue]);
+
+        world.entity_mut(also_red).insert(Team("blue"));
+        world.flush();
+
+        assert_eq!(world.entities_with_value::<Team, _>(&"red"), [red]);
+        assert_eq!(
+            world.entities_with_value::<Team, _>(&"blue"),
+            [blue, also_red]
+        );
+
+        world.entity_mut(blue).remove::<Team>();
+        world.flush();
+
+        assert_eq!(world.entities_with_value::<Team, _>(&"blue"), [also_red]);
+    }
+
+    #[test]
+    fn index_by_value_is_idempotent_for_the_same_key_type() {
+        let mut world = World::default();
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+        // Registering the same (component, key) pair again must not panic.
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "was already called with a different key type")]
+    fn index_by_value_panics_on_conflicting_key_type() {
+        let mut world = World::default();
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+        world.index_by_value::<Team, usize>(|team| team.0.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "already claimed")]
+    fn index_by_value_panics_on_component_claimed_by_filtered_observer() {
+        use crate::query::With;
+
+        let mut world = World::default();
+        world.add_observer_filtered::<E, (), With<Team>, _>(|_: On<E>| {});
+        world.flush();
+
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "already claimed")]
+    fn add_observer_filtered_panics_on_component_claimed_by_value_index() {
+        use crate::query::With;
+
+        let mut world = World::default();
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+
+        world.add_observer_filtered::<E, (), With<Team>, _>(|_: On<E>| {});
+    }
+
+    #[test]
+    fn trigger_targets_with_value_only_fires_matching_entities() {
+        let mut world = World::default();
+        world.init_resource::<Num>();
+        world.index_by_value::<Team, &'static str>(|team| team.0);
+
+        world
+            .spawn(Team("red"))
+            .observe(|_: On<E>, mut res: ResMut<Num>| res.0 += 1);
+        world
+            .spawn(Team("blue"))
+            .observe(|_: On<E>, mut res: ResMut<Num>| res.0 += 10);
+        world.flush();
+
+        world.trigger_targets_with_value::<E, Team, _>(E, &"red");
+
+        assert_eq!(world.resource::<Num>().0, 1);
+    }
 }